@@ -28,8 +28,42 @@ use core::sync::atomic::AtomicUsize;
 use core::sync::atomic::Ordering;
 
 
+/// The error reported when constructing an ID would require
+/// exceeding the representable range of the underlying integer type.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OverflowError;
+
+impl Debug for OverflowError {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    f.debug_struct("OverflowError").finish()
+  }
+}
+
+impl Display for OverflowError {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    write!(
+      f,
+      "overflow detected; please use a larger integer type or reconsider your use case"
+    )
+  }
+}
+
+// `core::error::Error` needs no `std` at all, only a high-enough Rust
+// version (it was stabilized in `core` as of 1.81), so this impl is
+// unconditional rather than hidden behind a feature no one can turn
+// on.
+impl core::error::Error for OverflowError {}
+
+
 macro_rules! IdImpl {
-  ( $(#[$docs:meta])* struct $name: ident, $int_type:ty, $non_zero_type:ty, $atomic_type: ty ) => {
+  (
+    $(#[$docs:meta])* struct $name: ident,
+    $(#[$gen_docs:meta])* struct $gen_name: ident,
+    $(#[$lazy_docs:meta])* struct $lazy_name: ident,
+    $int_type:ty, $non_zero_type:ty, $atomic_type: ty
+  ) => {
     $(#[$docs])*
     #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
     #[repr(transparent)]
@@ -50,13 +84,30 @@ macro_rules! IdImpl {
       /// - `id` should be unique with respect to other IDs created for this
       ///   `T` to preserve the invariant that IDs are unique
       #[inline]
-      pub unsafe fn new_unchecked(id: $int_type) -> Self {
+      pub const unsafe fn new_unchecked(id: $int_type) -> Self {
         Self {
           id: unsafe { <$non_zero_type>::new_unchecked(id) },
           phantom: PhantomData,
         }
       }
 
+      /// Create a new unique ID, reporting an error instead of
+      /// panicking if the underlying counter overflowed.
+      #[inline]
+      pub fn try_new() -> core::result::Result<Self, OverflowError> {
+        // The generator backing this global ID space; it is shared
+        // by all `T`, hence the `()` phantom type, and has a
+        // lifetime that is the entire program's.
+        static GENERATOR: $gen_name<()> = $gen_name::new();
+
+        GENERATOR.try_next().map(|id| {
+          // SAFETY: `id` was minted by `GENERATOR`, so it is
+          //         non-zero; uniqueness for this `T` follows from
+          //         `GENERATOR` being the sole source of IDs here.
+          unsafe { Self::new_unchecked(id.get()) }
+        })
+      }
+
       /// Create a new unique ID.
       ///
       /// # Panics
@@ -64,26 +115,28 @@ macro_rules! IdImpl {
       /// counter occurred.
       #[inline]
       pub fn new() -> Self {
-        static NEXT_ID: $atomic_type = <$atomic_type>::new(1);
-
-        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
-        assert_ne!(
-          id, 0,
-          "overflow detected; please use a larger integer to or reconsider your use case"
-        );
-
-        // SAFETY: The provided ID cannot be 0 (unless we overflow, in which
-        //         case we have other problems). We ensure uniqueness
-        //         because we increment IDs and this is the only constructor
-        //         for ID objects.
-        unsafe { Self::new_unchecked(id) }
+        Self::try_new().expect(
+          "overflow detected; please use a larger integer type or reconsider your use case",
+        )
       }
 
       /// Retrieve the underlying integer value.
       #[inline]
-      pub fn get(self) -> $int_type {
+      pub const fn get(self) -> $int_type {
         self.id.get()
       }
+
+      /// The largest value usable for this ID type.
+      ///
+      /// This is one less than the backing integer type's true
+      /// maximum, reserving that top value as a sentinel the way
+      /// regex-automata's `StateID`/`PatternID` do. [`TryFrom`]
+      /// rejects any value exceeding it, and [`IdGenerator`] never
+      /// mints such a value either, so every ID this type ever produces can be
+      /// round-tripped through [`TryFrom`]. This is a guard against
+      /// logic bugs when reconstructing IDs from external input, not
+      /// a memory-safety guarantee.
+      pub const MAX: $int_type = <$int_type>::MAX - 1;
     }
 
     impl<T> Default for $name<T> {
@@ -108,10 +161,231 @@ macro_rules! IdImpl {
         write!(f, "{}", self.id)
       }
     }
+
+    impl<T> core::convert::TryFrom<$int_type> for $name<T> {
+      type Error = OverflowError;
+
+      /// Reconstruct an ID from a raw integer value, e.g. one
+      /// obtained through serialization or an FFI boundary.
+      ///
+      /// This returns an error for `0` as well as for any value
+      /// exceeding [`MAX`][Self::MAX].
+      #[inline]
+      fn try_from(value: $int_type) -> core::result::Result<Self, Self::Error> {
+        if value == 0 || value > Self::MAX {
+          return Err(OverflowError);
+        }
+
+        // SAFETY: `value` was just verified to be non-zero.
+        Ok(unsafe { Self::new_unchecked(value) })
+      }
+    }
+
+    $(#[$gen_docs])*
+    pub struct $gen_name<T> {
+      next_id: $atomic_type,
+      phantom: PhantomData<T>,
+    }
+
+    impl<T> $gen_name<T> {
+      /// Create a new generator, with its first minted ID being `1`.
+      #[inline]
+      pub const fn new() -> Self {
+        Self::with_start(1)
+      }
+
+      /// Create a new generator whose first minted ID is `value`.
+      #[inline]
+      pub const fn with_start(value: $int_type) -> Self {
+        Self {
+          next_id: <$atomic_type>::new(value),
+          phantom: PhantomData,
+        }
+      }
+
+      /// Produce the next unique ID, reporting an error instead of
+      /// panicking if the underlying counter overflowed.
+      ///
+      /// Once the counter is exhausted it latches: every subsequent
+      /// call keeps reporting an error instead of wrapping back
+      /// around and re-minting already handed-out IDs.
+      #[inline]
+      pub fn try_next(&self) -> core::result::Result<$name<T>, OverflowError> {
+        let mut id = self.next_id.load(Ordering::Relaxed);
+        loop {
+          // `0` marks a latched, exhausted counter, and `$name::MAX`
+          // is the last value `$name` is willing to accept (the raw
+          // integer maximum is reserved as a sentinel), so either one
+          // means the counter must not advance any further.
+          if id == 0 || id > $name::<T>::MAX {
+            // Make the latch stick so that a racing thread that
+            // observed the same `id` doesn't get to re-mint it on a
+            // subsequent call; if another thread already latched it,
+            // this simply fails and we report the error regardless.
+            let _ = self.next_id.compare_exchange(
+              id,
+              0,
+              Ordering::Relaxed,
+              Ordering::Relaxed,
+            );
+            return Err(OverflowError);
+          }
+
+          // SAFETY: `id` is in `1..=$name::MAX`, so `id + 1` cannot
+          //         overflow the underlying integer type.
+          match self.next_id.compare_exchange_weak(
+            id,
+            id + 1,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+          ) {
+            // SAFETY: `id` cannot be 0. We ensure uniqueness because
+            //         we only ever install `id + 1` once `id` itself
+            //         has been handed out, and this is the only means
+            //         by which this generator creates ID objects.
+            Ok(..) => return Ok(unsafe { $name::new_unchecked(id) }),
+            Err(observed) => id = observed,
+          }
+        }
+      }
+
+      /// Produce the next unique ID.
+      ///
+      /// # Panics
+      /// This method panics if an overflow of the underlying counter
+      /// occurred.
+      #[inline]
+      pub fn next(&self) -> $name<T> {
+        self.try_next().expect(
+          "overflow detected; please use a larger integer type or reconsider your use case",
+        )
+      }
+    }
+
+    impl<T> Default for $gen_name<T> {
+      /// Create a new generator, with its first minted ID being `1`.
+      #[inline]
+      fn default() -> Self {
+        Self::new()
+      }
+    }
+
+    $(#[$lazy_docs])*
+    pub struct $lazy_name<T> {
+      id: $atomic_type,
+      phantom: PhantomData<T>,
+    }
+
+    impl<T> $lazy_name<T> {
+      /// Create a new, unassigned lazy ID.
+      ///
+      /// No unique value is consumed until the first call to
+      /// [`get`][Self::get].
+      #[inline]
+      pub const fn new() -> Self {
+        Self {
+          id: <$atomic_type>::new(0),
+          phantom: PhantomData,
+        }
+      }
+
+      /// Retrieve the ID, lazily assigning it from the global
+      /// counter on first access.
+      ///
+      /// # Panics
+      /// This method panics if an overflow of the underlying global
+      /// counter occurred.
+      #[inline]
+      pub fn get(&self) -> $name<T> {
+        let id = self.id.load(Ordering::Relaxed);
+        if id != 0 {
+          // SAFETY: `id` was validated to be non-zero before being
+          //         stored below.
+          return unsafe { $name::new_unchecked(id) };
+        }
+
+        let new_id = $name::<T>::new().get();
+        // If another thread beat us to it, use the value it
+        // installed instead of the one we just minted; the one we
+        // minted is simply discarded.
+        let id = match self.id.compare_exchange(
+          0,
+          new_id,
+          Ordering::Relaxed,
+          Ordering::Relaxed,
+        ) {
+          Ok(..) => new_id,
+          Err(id) => id,
+        };
+
+        // SAFETY: `id` is either `new_id`, which is non-zero, or the
+        //         value another thread installed, which can only
+        //         ever be a non-zero value produced the same way.
+        unsafe { $name::new_unchecked(id) }
+      }
+    }
+
+    impl<T> Default for $lazy_name<T> {
+      /// Create a new, unassigned lazy ID.
+      #[inline]
+      fn default() -> Self {
+        Self::new()
+      }
+    }
+
+    impl<T> Clone for $lazy_name<T> {
+      /// Clone the lazy ID.
+      ///
+      /// If `self` is unassigned, the clone is unassigned too and
+      /// will be assigned its own, independent value on first access.
+      /// If `self` is already assigned, the clone carries the same
+      /// value.
+      #[inline]
+      fn clone(&self) -> Self {
+        Self {
+          id: <$atomic_type>::new(self.id.load(Ordering::Relaxed)),
+          phantom: PhantomData,
+        }
+      }
+    }
+
+    impl<T> Debug for $lazy_name<T> {
+      #[inline]
+      fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.id.load(Ordering::Relaxed) {
+          0 => write!(f, "{}(unassigned)", stringify!($lazy_name)),
+          id => write!(f, "{}({id})", stringify!($lazy_name)),
+        }
+      }
+    }
   }
 }
 
 
+/// Implement a fallible [`TryFrom<usize>`] conversion for an `Id*`
+/// type whose backing integer is not `usize` itself.
+macro_rules! TryFromUsizeImpl {
+  ($name:ident, $int_type:ty) => {
+    impl<T> core::convert::TryFrom<usize> for $name<T> {
+      type Error = OverflowError;
+
+      /// Reconstruct this ID from a `usize`, e.g. one obtained
+      /// through serialization or an FFI boundary.
+      ///
+      /// This returns an error for `0` as well as for any value
+      /// exceeding [`MAX`][Self::MAX], which includes every value that
+      /// does not fit in the underlying integer type.
+      #[inline]
+      fn try_from(value: usize) -> core::result::Result<Self, Self::Error> {
+        <$int_type>::try_from(value)
+          .map_err(|_| OverflowError)
+          .and_then(Self::try_from)
+      }
+    }
+  };
+}
+
+
 IdImpl! {
   /// A struct representing IDs usable for various purposes.
   ///
@@ -158,7 +432,63 @@ IdImpl! {
   /// // other. The same can be said about the relationship to built-in
   /// // numeric types such as `usize` or `u64`.
   /// ```
-  struct Id, usize, NonZeroUsize, AtomicUsize
+  struct Id,
+  /// A generator producing unique [`Id`] instances.
+  ///
+  /// Unlike [`Id::new`], which pulls from a single generator shared
+  /// by all [`Id`]s regardless of `T`, an `IdGenerator` owns its own
+  /// independent counter. IDs produced by one generator are
+  /// guaranteed unique only with respect to that generator,
+  /// enabling per-arena or per-session ID spaces as well as
+  /// deterministic, resettable sequences in tests.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use uid::Id as IdT;
+  /// use uid::IdGenerator as IdGeneratorT;
+  ///
+  /// #[derive(Copy, Clone, Eq, PartialEq)]
+  /// struct T(());
+  ///
+  /// type Id = IdT<T>;
+  /// type IdGenerator = IdGeneratorT<T>;
+  ///
+  /// let generator = IdGenerator::new();
+  /// let id1 = generator.next();
+  /// let id2 = generator.next();
+  ///
+  /// assert_ne!(id1, id2);
+  /// ```
+  struct IdGenerator,
+  /// An [`Id`] that defers consuming a unique value until it is
+  /// first observed via [`get`][Self::get].
+  ///
+  /// This is useful for types that embed an `Id` but do not always
+  /// end up using it, such as types deriving [`Default`] where
+  /// constructing a value should not by itself burn through the
+  /// global counter.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use uid::LazyId as LazyIdT;
+  ///
+  /// #[derive(Copy, Clone, Eq, PartialEq)]
+  /// struct T(());
+  ///
+  /// type LazyId = LazyIdT<T>;
+  ///
+  /// #[derive(Default)]
+  /// struct Thing {
+  ///   id: LazyId,
+  /// }
+  ///
+  /// // No ID has been minted yet at this point.
+  /// let thing = Thing::default();
+  /// // Only now is a unique ID assigned.
+  /// let id = thing.id.get();
+  /// ```
+  struct LazyId,
+  usize, NonZeroUsize, AtomicUsize
 }
 IdImpl! {
   /// A struct representing IDs usable for various purposes using an eight
@@ -166,33 +496,88 @@ IdImpl! {
   ///
   /// Please see the [`Id`] type for more general information and usage
   /// examples.
-  struct IdU8, u8, NonZeroU8, AtomicU8
+  struct IdU8,
+  /// A generator producing unique [`IdU8`] instances.
+  ///
+  /// Please see the [`IdGenerator`] type for more general information
+  /// and usage examples.
+  struct IdU8Generator,
+  /// A lazily-assigned [`IdU8`].
+  ///
+  /// Please see the [`LazyId`] type for more general information and
+  /// usage examples.
+  struct LazyIdU8,
+  u8, NonZeroU8, AtomicU8
 }
+
+TryFromUsizeImpl! { IdU8, u8 }
+
 IdImpl! {
   /// A struct representing IDs usable for various purposes using an 16
   /// bit wide unsigned integer.
   ///
   /// Please see the [`Id`] type for more general information and usage
   /// examples.
-  struct IdU16, u16, NonZeroU16, AtomicU16
+  struct IdU16,
+  /// A generator producing unique [`IdU16`] instances.
+  ///
+  /// Please see the [`IdGenerator`] type for more general information
+  /// and usage examples.
+  struct IdU16Generator,
+  /// A lazily-assigned [`IdU16`].
+  ///
+  /// Please see the [`LazyId`] type for more general information and
+  /// usage examples.
+  struct LazyIdU16,
+  u16, NonZeroU16, AtomicU16
 }
+
+TryFromUsizeImpl! { IdU16, u16 }
+
 IdImpl! {
   /// A struct representing IDs usable for various purposes using an 32
   /// bit wide unsigned integer.
   ///
   /// Please see the [`Id`] type for more general information and usage
   /// examples.
-  struct IdU32, u32, NonZeroU32, AtomicU32
+  struct IdU32,
+  /// A generator producing unique [`IdU32`] instances.
+  ///
+  /// Please see the [`IdGenerator`] type for more general information
+  /// and usage examples.
+  struct IdU32Generator,
+  /// A lazily-assigned [`IdU32`].
+  ///
+  /// Please see the [`LazyId`] type for more general information and
+  /// usage examples.
+  struct LazyIdU32,
+  u32, NonZeroU32, AtomicU32
 }
+
+TryFromUsizeImpl! { IdU32, u32 }
+
 IdImpl! {
   /// A struct representing IDs usable for various purposes using an 64
   /// bit wide unsigned integer.
   ///
   /// Please see the [`Id`] type for more general information and usage
   /// examples.
-  struct IdU64, u64, NonZeroU64, AtomicU64
+  struct IdU64,
+  /// A generator producing unique [`IdU64`] instances.
+  ///
+  /// Please see the [`IdGenerator`] type for more general information
+  /// and usage examples.
+  struct IdU64Generator,
+  /// A lazily-assigned [`IdU64`].
+  ///
+  /// Please see the [`LazyId`] type for more general information and
+  /// usage examples.
+  struct LazyIdU64,
+  u64, NonZeroU64, AtomicU64
 }
 
+TryFromUsizeImpl! { IdU64, u64 }
+
 
 #[cfg(test)]
 mod tests {
@@ -288,4 +673,162 @@ mod tests {
       let _ = IdU8::<()>::new();
     });
   }
+
+  /// Check that [`try_new`][IdU16::try_new] reports an
+  /// [`OverflowError`] instead of panicking once the underlying
+  /// counter is exhausted.
+  #[test]
+  fn try_new_reports_overflow() {
+    for _ in 0..IdU16::<()>::MAX {
+      let _id = IdU16::<()>::try_new().unwrap();
+    }
+
+    let err = IdU16::<()>::try_new().unwrap_err();
+    assert_eq!(format!("{err:?}"), "OverflowError");
+    assert_eq!(
+      format!("{err}"),
+      "overflow detected; please use a larger integer type or reconsider your use case"
+    );
+
+    // The counter is latched, so it keeps reporting the error instead
+    // of wrapping back around and re-minting earlier IDs.
+    assert_eq!(IdU16::<()>::try_new().unwrap_err(), OverflowError);
+  }
+
+  /// Check that an [`IdGenerator`] produces increasing, unique IDs.
+  #[test]
+  fn id_generator_increases() {
+    let generator = IdGenerator::<u32>::new();
+    let id1 = generator.next();
+    let id2 = generator.next();
+
+    assert!(id2 > id1);
+  }
+
+  /// Check that independent [`IdGenerator`] instances mint IDs from
+  /// independent counters.
+  #[test]
+  fn id_generator_is_independent() {
+    let generator1 = IdGenerator::<u32>::with_start(1);
+    let generator2 = IdGenerator::<u32>::with_start(1);
+
+    assert_eq!(generator1.next().get(), generator2.next().get());
+  }
+
+  /// Check that an [`IdGenerator`] reports an [`OverflowError`]
+  /// instead of panicking once its counter is exhausted.
+  #[test]
+  fn id_generator_reports_overflow() {
+    let generator = IdU8Generator::<()>::new();
+    for _ in 0..IdU8::<()>::MAX {
+      let _id = generator.try_next().unwrap();
+    }
+
+    assert_eq!(generator.try_next().unwrap_err(), OverflowError);
+  }
+
+  /// Check that an exhausted [`IdGenerator`] never mints the reserved
+  /// sentinel value and latches instead of wrapping around and
+  /// re-minting IDs it already handed out.
+  #[test]
+  fn id_generator_overflow_latches() {
+    let generator = IdU8Generator::<()>::new();
+    let mut ids = Vec::new();
+    for _ in 0..300 {
+      match generator.try_next() {
+        Ok(id) => ids.push(id.get()),
+        Err(..) => break,
+      }
+    }
+
+    assert_eq!(ids.len(), usize::from(IdU8::<()>::MAX));
+    assert!(!ids.contains(&u8::MAX));
+
+    // Once exhausted, every further call keeps reporting the error.
+    assert_eq!(generator.try_next().unwrap_err(), OverflowError);
+    assert_eq!(generator.try_next().unwrap_err(), OverflowError);
+  }
+
+  /// Check that a [`LazyId`] does not consume a unique value until
+  /// it is first observed.
+  #[test]
+  fn lazy_id_defers_assignment() {
+    type TestLazyId = LazyId<u32>;
+
+    let lazy = TestLazyId::default();
+    assert_eq!(format!("{lazy:?}"), "LazyId(unassigned)");
+
+    let id1 = lazy.get();
+    let id2 = lazy.get();
+    assert_eq!(id1, id2);
+  }
+
+  /// Check that cloning an unassigned [`LazyId`] produces an
+  /// independent, unassigned instance, while cloning an assigned one
+  /// carries over its value.
+  #[test]
+  fn lazy_id_clone() {
+    type TestLazyId = LazyId<u32>;
+
+    let lazy1 = TestLazyId::default();
+    let lazy2 = lazy1.clone();
+    assert_ne!(lazy1.get(), lazy2.get());
+
+    let lazy3 = lazy1.clone();
+    assert_eq!(lazy1.get(), lazy3.get());
+  }
+
+  /// Check that [`TryFrom`] reconstructs a valid [`Id`] from a raw
+  /// integer and rejects `0`.
+  #[test]
+  fn try_from_int() {
+    let id = TestId::try_from(1337).unwrap();
+    assert_eq!(id.get(), 1337);
+
+    let err = TestId::try_from(0).unwrap_err();
+    assert_eq!(err, OverflowError);
+  }
+
+  /// Check that [`TryFrom<usize>`] rejects values that do not fit in
+  /// the narrower underlying integer type.
+  #[test]
+  fn try_from_usize_out_of_range() {
+    let id = IdU8::<()>::try_from(42_usize).unwrap();
+    assert_eq!(id.get(), 42);
+
+    let err = IdU8::<()>::try_from(usize::from(u8::MAX) + 1).unwrap_err();
+    assert_eq!(err, OverflowError);
+  }
+
+  /// Check that `MAX` reflects the representable range of the
+  /// underlying integer type.
+  #[test]
+  fn max() {
+    assert_eq!(IdU8::<()>::MAX, u8::MAX - 1);
+    assert_eq!(IdU16::<()>::MAX, u16::MAX - 1);
+    assert_eq!(IdU32::<()>::MAX, u32::MAX - 1);
+    assert_eq!(IdU64::<()>::MAX, u64::MAX - 1);
+  }
+
+  /// Check that [`TryFrom`] rejects a value that exceeds [`MAX`],
+  /// even though it would otherwise fit in the backing integer type.
+  #[test]
+  fn try_from_rejects_reserved_sentinel() {
+    let err = IdU8::<()>::try_from(u8::MAX).unwrap_err();
+    assert_eq!(err, OverflowError);
+
+    let id = IdU8::<()>::try_from(IdU8::<()>::MAX).unwrap();
+    assert_eq!(id.get(), IdU8::<()>::MAX);
+  }
+
+  /// Check that [`Id::new_unchecked`][TestId::new_unchecked] and
+  /// [`Id::get`][TestId::get] can be used in `const` contexts, e.g.
+  /// to declare fixed sentinel IDs at compile time.
+  #[test]
+  fn const_usage() {
+    const SENTINEL: TestId = unsafe { TestId::new_unchecked(1) };
+    const SENTINEL_VALUE: usize = SENTINEL.get();
+
+    assert_eq!(SENTINEL_VALUE, 1);
+  }
 }